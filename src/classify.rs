@@ -0,0 +1,71 @@
+//! Heuristic classification of raw 32 bit values into their likely code space.
+
+use crate::hresult::HResult;
+
+/// Code space a raw 32 bit value most likely belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CodeKind {
+    /// HRESULT, including NTSTATUS values mapped with the N bit.
+    Hresult,
+    /// NTSTATUS value.
+    NtStatus,
+    /// Win32 error code.
+    Win32,
+    /// Value that is valid in more than one code space.
+    Ambiguous,
+}
+
+/// Classifies a raw 32 bit value using the layouts documented at the crate root:
+///
+/// - `0` is a success value in every code space, so it's `Ambiguous`.
+/// - Values up to `0xFFFF` fit a Win32 error code, so they're `Win32`.
+/// - Failures with the N bit set are NT-mapped HRESULTs, so they're `Hresult`.
+/// - Values with bit 30 set have an informational or error NT severity, which
+///   the HRESULT layout reserves, so they're `NtStatus`.
+/// - Remaining failures are `Hresult`.
+/// - Remaining success values share the same layout in HRESULT and NTSTATUS, so
+///   they're `Ambiguous`.
+///
+/// These are heuristics only; for example `1` is both `ERROR_INVALID_FUNCTION`
+/// and `S_FALSE`, and NTSTATUS warnings are indistinguishable from HRESULT failures.
+pub fn classify(value: i32) -> CodeKind {
+    let hr = HResult(value);
+    if value == 0 {
+        CodeKind::Ambiguous
+    } else if (value as u32) <= 0xFFFF {
+        CodeKind::Win32
+    } else if hr.is_failure() && hr.is_ntmapped() {
+        CodeKind::Hresult
+    } else if hr.reserved_r() {
+        CodeKind::NtStatus
+    } else if hr.is_failure() {
+        CodeKind::Hresult
+    } else {
+        CodeKind::Ambiguous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_win32_mapped_hresult() {
+        assert_eq!(classify(0x8007_0002_u32 as i32), CodeKind::Hresult);
+    }
+
+    #[test]
+    fn classifies_ntstatus_error() {
+        assert_eq!(classify(0xC000_0005_u32 as i32), CodeKind::NtStatus);
+    }
+
+    #[test]
+    fn classifies_win32_error() {
+        assert_eq!(classify(2), CodeKind::Win32);
+    }
+
+    #[test]
+    fn classifies_zero_as_ambiguous() {
+        assert_eq!(classify(0), CodeKind::Ambiguous);
+    }
+}
@@ -0,0 +1,75 @@
+//! Source code generation from code tables such as `errors::all_com`.
+
+use crate::hresult::HResult;
+use std::fmt;
+
+/// Writes a C `#define` line, such as `#define E_FAIL ((HRESULT)0x80004005L)`, for
+/// every entry of a table of HRESULTs.
+pub fn write_c_header<'a, I, W>(table: I, out: &mut W) -> fmt::Result
+where
+    I: IntoIterator<Item = &'a (&'a str, HResult, &'a str)>,
+    W: fmt::Write,
+{
+    for &(name, value, _) in table {
+        writeln!(out, "#define {} ((HRESULT)0x{:08X}L)", name, value.0 as u32)?;
+    }
+    Ok(())
+}
+
+/// Writes a Rust `pub const` declaration, such as
+/// `pub const E_FAIL: i32 = 0x80004005u32 as i32;`, for every entry of a table of
+/// HRESULTs, documented with the entry's message text.
+pub fn write_rust_consts<'a, I, W>(table: I, out: &mut W) -> fmt::Result
+where
+    I: IntoIterator<Item = &'a (&'a str, HResult, &'a str)>,
+    W: fmt::Write,
+{
+    for &(name, value, message) in table {
+        for line in message.lines() {
+            writeln!(out, "/// {}", line)?;
+        }
+        writeln!(
+            out,
+            "pub const {}: i32 = 0x{:08X}u32 as i32;",
+            name, value.0 as u32
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::com::{E_FAIL, S_OK};
+
+    #[test]
+    fn writes_c_defines() {
+        let table = [
+            ("S_OK", S_OK, "Operation successful"),
+            ("E_FAIL", E_FAIL, "Unspecified error"),
+        ];
+        let mut header = String::new();
+        write_c_header(table.iter(), &mut header).unwrap();
+        assert_eq!(
+            header,
+            "#define S_OK ((HRESULT)0x00000000L)\n#define E_FAIL ((HRESULT)0x80004005L)\n"
+        );
+    }
+
+    #[test]
+    fn writes_rust_consts() {
+        let table = [
+            ("S_OK", S_OK, "Operation successful"),
+            ("E_FAIL", E_FAIL, "Unspecified error"),
+        ];
+        let mut consts = String::new();
+        write_rust_consts(table.iter(), &mut consts).unwrap();
+        assert_eq!(
+            consts,
+            "/// Operation successful\n\
+             pub const S_OK: i32 = 0x00000000u32 as i32;\n\
+             /// Unspecified error\n\
+             pub const E_FAIL: i32 = 0x80004005u32 as i32;\n"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! Standard COM result codes defined in winerror.h.
+
+use crate::hresult::HResult;
+
+error_codes! {
+    HResult;
+    S_OK = HResult(0x0000_0000), "Operation successful";
+    S_FALSE = HResult(0x0000_0001), "Operation successful but returned no results";
+    E_NOTIMPL = HResult(0x8000_4001_u32 as i32), "Not implemented";
+    E_NOINTERFACE = HResult(0x8000_4002_u32 as i32), "No such interface supported";
+    E_POINTER = HResult(0x8000_4003_u32 as i32), "Invalid pointer";
+    E_ABORT = HResult(0x8000_4004_u32 as i32), "Operation aborted";
+    E_FAIL = HResult(0x8000_4005_u32 as i32), "Unspecified error";
+    E_UNEXPECTED = HResult(0x8000_FFFF_u32 as i32), "Catastrophic failure";
+    E_ACCESSDENIED = HResult(0x8007_0005_u32 as i32), "General access denied error";
+    E_HANDLE = HResult(0x8007_0006_u32 as i32), "Invalid handle";
+    E_OUTOFMEMORY = HResult(0x8007_000E_u32 as i32), "Ran out of memory";
+    E_INVALIDARG = HResult(0x8007_0057_u32 as i32), "One or more arguments are invalid";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn e_invalidarg_decodes_to_win32_invalid_parameter() {
+        assert_eq!(E_INVALIDARG.0 as u32, 0x8007_0057);
+        assert_eq!(E_INVALIDARG.facility(), 7);
+        assert_eq!(E_INVALIDARG.code(), 0x57);
+    }
+
+    #[test]
+    fn s_false_is_one() {
+        assert_eq!(S_FALSE.0, 0x0000_0001);
+        assert!(S_FALSE.is_success());
+    }
+
+    #[test]
+    fn table_carries_names_and_messages() {
+        let &(name, value, message) = CODES
+            .iter()
+            .find(|&&(name, _, _)| name == "E_INVALIDARG")
+            .unwrap();
+        assert_eq!(name, "E_INVALIDARG");
+        assert_eq!(value, E_INVALIDARG);
+        assert_eq!(message, "One or more arguments are invalid");
+    }
+}
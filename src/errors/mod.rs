@@ -0,0 +1,89 @@
+//! Well-known error codes defined in the Windows headers.
+
+use crate::hresult::HResult;
+
+/// Declares documented code constants together with the module's `CODES` table
+/// of symbolic names, values and message text, from a single list.
+macro_rules! error_codes {
+    ($ty:ty; $($name:ident = $value:expr, $message:literal;)*) => {
+        $(
+            #[doc = $message]
+            pub const $name: $ty = $value;
+        )*
+
+        /// Symbolic names, values and message text of all the codes in this module.
+        pub(crate) static CODES: &[(&str, $ty, &str)] = &[
+            $((stringify!($name), $name, $message),)*
+        ];
+    };
+}
+
+pub mod codegen;
+pub mod com;
+pub mod win32;
+
+/// Returns the symbolic names, values and message text of all the codes in `win32`.
+pub fn all_win32() -> impl Iterator<Item = &'static (&'static str, u32, &'static str)> {
+    win32::CODES.iter()
+}
+
+/// Returns the symbolic names, values and message text of all the codes in `com`.
+pub fn all_com() -> impl Iterator<Item = &'static (&'static str, HResult, &'static str)> {
+    com::CODES.iter()
+}
+
+/// Returns the entries of a code table, such as `all_win32` or `all_com`, whose
+/// message text contains the supplied needle, ignoring case, in table order.
+pub fn find_by_message<'a, T: 'a, I>(table: I, needle: &str) -> Vec<&'a (&'a str, T, &'a str)>
+where
+    I: IntoIterator<Item = &'a (&'a str, T, &'a str)>,
+{
+    let needle = needle.to_lowercase();
+    table
+        .into_iter()
+        .filter(|&&(_, _, message)| message.to_lowercase().contains(&needle))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_not_empty() {
+        assert!(all_win32().next().is_some());
+        assert!(all_com().next().is_some());
+    }
+
+    #[test]
+    fn all_com_contains_e_fail() {
+        let &(_, value, message) = all_com().find(|&&(name, _, _)| name == "E_FAIL").unwrap();
+        assert_eq!(value, com::E_FAIL);
+        assert_eq!(message, "Unspecified error");
+    }
+
+    #[test]
+    fn all_win32_contains_file_not_found() {
+        assert!(
+            all_win32().any(|&(name, value, _)| name == "ERROR_FILE_NOT_FOUND"
+                && value == win32::ERROR_FILE_NOT_FOUND)
+        );
+    }
+
+    #[test]
+    fn find_by_message_matches_ignoring_case() {
+        let found = find_by_message(all_win32(), "CANNOT FIND");
+        let names: Vec<_> = found.iter().map(|&&(name, _, _)| name).collect();
+        assert_eq!(names, ["ERROR_FILE_NOT_FOUND", "ERROR_PATH_NOT_FOUND"]);
+
+        let found = find_by_message(all_com(), "unspecified");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, com::E_FAIL);
+    }
+
+    #[test]
+    fn find_by_message_returns_nothing_on_miss() {
+        assert!(find_by_message(all_win32(), "no such message text").is_empty());
+        assert!(find_by_message(all_com(), "no such message text").is_empty());
+    }
+}
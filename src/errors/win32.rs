@@ -0,0 +1,50 @@
+//! Common Win32 error codes defined in winerror.h, in the `FACILITY_WIN32` space.
+//!
+//! Use `hresult::hresult_from_win32` to map them into HRESULTs.
+
+error_codes! {
+    u32;
+    ERROR_SUCCESS = 0, "The operation completed successfully.";
+    ERROR_INVALID_FUNCTION = 1, "Incorrect function.";
+    ERROR_FILE_NOT_FOUND = 2, "The system cannot find the file specified.";
+    ERROR_PATH_NOT_FOUND = 3, "The system cannot find the path specified.";
+    ERROR_TOO_MANY_OPEN_FILES = 4, "The system cannot open the file.";
+    ERROR_ACCESS_DENIED = 5, "Access is denied.";
+    ERROR_INVALID_HANDLE = 6, "The handle is invalid.";
+    ERROR_NOT_ENOUGH_MEMORY = 8, "Not enough memory resources are available to process this command.";
+    ERROR_INVALID_DATA = 13, "The data is invalid.";
+    ERROR_OUTOFMEMORY = 14, "Not enough memory resources are available to complete this operation.";
+    ERROR_NOT_READY = 21, "The device is not ready.";
+    ERROR_SHARING_VIOLATION = 32, "The process cannot access the file because it is being used by another process.";
+    ERROR_HANDLE_EOF = 38, "Reached the end of the file.";
+    ERROR_NOT_SUPPORTED = 50, "The request is not supported.";
+    ERROR_FILE_EXISTS = 80, "The file exists.";
+    ERROR_INVALID_PARAMETER = 87, "The parameter is incorrect.";
+    ERROR_BROKEN_PIPE = 109, "The pipe has been ended.";
+    ERROR_INSUFFICIENT_BUFFER = 122, "The data area passed to a system call is too small.";
+    ERROR_INVALID_NAME = 123, "The filename, directory name, or volume label syntax is incorrect.";
+    ERROR_ALREADY_EXISTS = 183, "Cannot create a file when that file already exists.";
+    ERROR_MORE_DATA = 234, "More data is available.";
+    ERROR_NO_MORE_ITEMS = 259, "No more data is available.";
+    ERROR_OPERATION_ABORTED = 995, "The I/O operation has been aborted because of either a thread exit or an application request.";
+    ERROR_IO_PENDING = 997, "Overlapped I/O operation is in progress.";
+    ERROR_NOT_FOUND = 1168, "Element not found.";
+    ERROR_TIMEOUT = 1460, "This operation returned because the timeout period expired.";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_not_found_has_expected_value_and_name() {
+        assert_eq!(ERROR_FILE_NOT_FOUND, 2);
+        let &(name, value, message) = CODES
+            .iter()
+            .find(|&&(_, value, _)| value == ERROR_FILE_NOT_FOUND)
+            .unwrap();
+        assert_eq!(name, "ERROR_FILE_NOT_FOUND");
+        assert_eq!(value, 2);
+        assert_eq!(message, "The system cannot find the file specified.");
+    }
+}
@@ -0,0 +1,480 @@
+//! Standard facility codes defined in winerror.h.
+
+use crate::hresult::HResult;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Facility code paired with its symbolic name.
+#[derive(Clone, Copy, Debug)]
+pub struct Facility {
+    value: i32,
+    symbolic_name: &'static str,
+}
+
+/// Facilities compare equal when their values match, regardless of symbolic name.
+impl PartialEq for Facility {
+    fn eq(&self, other: &Facility) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Facility {}
+
+impl Hash for Facility {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl Facility {
+    /// Creates a new facility from its value and symbolic name.
+    pub const fn new(value: i32, symbolic_name: &'static str) -> Facility {
+        Facility {
+            value,
+            symbolic_name,
+        }
+    }
+
+    /// Returns the facility code.
+    pub const fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Creates a new facility from its unsigned value and symbolic name.
+    pub const fn from_u32(value: u32, symbolic_name: &'static str) -> Facility {
+        Facility::new(value as i32, symbolic_name)
+    }
+
+    /// Returns the facility code as an unsigned value, for bit packing.
+    pub const fn as_u32(&self) -> u32 {
+        self.value as u32
+    }
+
+    /// Returns true if the facility code fits in the 12 bit field of the layout
+    /// documented at the crate root.
+    pub const fn fits(&self) -> bool {
+        self.as_u32() & !0xFFF == 0
+    }
+
+    /// Returns the symbolic name of the facility.
+    pub const fn symbolic_name(&self) -> &'static str {
+        self.symbolic_name
+    }
+
+    /// Returns an owned copy of the symbolic name of the facility.
+    pub fn symbolic_name_owned(&self) -> String {
+        self.symbolic_name.to_string()
+    }
+
+    /// Returns the inclusive `(min, max)` range of codes the facility can hold.
+    pub const fn code_range(&self) -> (i32, i32) {
+        (0, 0xFFFF)
+    }
+
+    /// Returns true if the supplied HRESULT belongs to this facility.
+    ///
+    /// The HRESULT facility field is only 11 bits wide, so facilities with values of
+    /// `0x800` or above, which still `fits()` the 12 bit field, never contain an HRESULT.
+    pub fn contains(&self, hr: HResult) -> bool {
+        hr.facility() == self.value
+    }
+}
+
+/// General codes that don't belong to any specific facility, such as `E_FAIL`.
+pub const FACILITY_NULL: Facility = Facility::new(0, "FACILITY_NULL");
+/// Remote procedure call (RPC) subsystem.
+pub const FACILITY_RPC: Facility = Facility::new(1, "FACILITY_RPC");
+/// COM dispatch (`IDispatch`) interfaces.
+pub const FACILITY_DISPATCH: Facility = Facility::new(2, "FACILITY_DISPATCH");
+/// OLE structured storage.
+pub const FACILITY_STORAGE: Facility = Facility::new(3, "FACILITY_STORAGE");
+/// Interface specific codes, defined by the interface that returns them.
+pub const FACILITY_ITF: Facility = Facility::new(4, "FACILITY_ITF");
+/// Win32 error codes mapped into HRESULTs.
+pub const FACILITY_WIN32: Facility = Facility::new(7, "FACILITY_WIN32");
+/// Windows subsystem.
+pub const FACILITY_WINDOWS: Facility = Facility::new(8, "FACILITY_WINDOWS");
+/// Security and SSPI subsystem.
+pub const FACILITY_SECURITY: Facility = Facility::new(9, "FACILITY_SECURITY");
+/// ActiveX controls.
+pub const FACILITY_CONTROL: Facility = Facility::new(10, "FACILITY_CONTROL");
+/// Certificate services.
+pub const FACILITY_CERT: Facility = Facility::new(11, "FACILITY_CERT");
+/// WinInet internet APIs.
+pub const FACILITY_INTERNET: Facility = Facility::new(12, "FACILITY_INTERNET");
+/// Windows Media Server.
+pub const FACILITY_MEDIASERVER: Facility = Facility::new(13, "FACILITY_MEDIASERVER");
+/// Microsoft Message Queue.
+pub const FACILITY_MSMQ: Facility = Facility::new(14, "FACILITY_MSMQ");
+/// Setup API.
+pub const FACILITY_SETUPAPI: Facility = Facility::new(15, "FACILITY_SETUPAPI");
+/// Smart card subsystem.
+pub const FACILITY_SCARD: Facility = Facility::new(16, "FACILITY_SCARD");
+/// COM+.
+pub const FACILITY_COMPLUS: Facility = Facility::new(17, "FACILITY_COMPLUS");
+/// Microsoft agent.
+pub const FACILITY_AAF: Facility = Facility::new(18, "FACILITY_AAF");
+/// .NET common language runtime.
+pub const FACILITY_URT: Facility = Facility::new(19, "FACILITY_URT");
+/// Audit collection service.
+pub const FACILITY_ACS: Facility = Facility::new(20, "FACILITY_ACS");
+/// DirectPlay.
+pub const FACILITY_DPLAY: Facility = Facility::new(21, "FACILITY_DPLAY");
+/// Ubiquitous memory introspection service.
+pub const FACILITY_UMI: Facility = Facility::new(22, "FACILITY_UMI");
+/// Side-by-side assemblies.
+pub const FACILITY_SXS: Facility = Facility::new(23, "FACILITY_SXS");
+/// Windows CE.
+pub const FACILITY_WINDOWS_CE: Facility = Facility::new(24, "FACILITY_WINDOWS_CE");
+/// HTTP support.
+pub const FACILITY_HTTP: Facility = Facility::new(25, "FACILITY_HTTP");
+/// Common logging support.
+pub const FACILITY_USERMODE_COMMONLOG: Facility = Facility::new(26, "FACILITY_USERMODE_COMMONLOG");
+/// Windows error reporting.
+pub const FACILITY_WER: Facility = Facility::new(27, "FACILITY_WER");
+/// User mode filter manager.
+pub const FACILITY_USERMODE_FILTER_MANAGER: Facility =
+    Facility::new(31, "FACILITY_USERMODE_FILTER_MANAGER");
+/// Background intelligent transfer service (BITS).
+pub const FACILITY_BACKGROUNDCOPY: Facility = Facility::new(32, "FACILITY_BACKGROUNDCOPY");
+/// Configuration services.
+pub const FACILITY_CONFIGURATION: Facility = Facility::new(33, "FACILITY_CONFIGURATION");
+/// State management services.
+pub const FACILITY_STATE_MANAGEMENT: Facility = Facility::new(34, "FACILITY_STATE_MANAGEMENT");
+/// Microsoft identity server.
+pub const FACILITY_METADIRECTORY: Facility = Facility::new(35, "FACILITY_METADIRECTORY");
+/// Windows update.
+pub const FACILITY_WINDOWSUPDATE: Facility = Facility::new(36, "FACILITY_WINDOWSUPDATE");
+/// Active directory.
+pub const FACILITY_DIRECTORYSERVICE: Facility = Facility::new(37, "FACILITY_DIRECTORYSERVICE");
+/// Graphics drivers.
+pub const FACILITY_GRAPHICS: Facility = Facility::new(38, "FACILITY_GRAPHICS");
+/// Windows shell.
+pub const FACILITY_SHELL: Facility = Facility::new(39, "FACILITY_SHELL");
+/// Trusted platform module services.
+pub const FACILITY_TPM_SERVICES: Facility = Facility::new(40, "FACILITY_TPM_SERVICES");
+/// Trusted platform module applications.
+pub const FACILITY_TPM_SOFTWARE: Facility = Facility::new(41, "FACILITY_TPM_SOFTWARE");
+/// User interface.
+pub const FACILITY_UI: Facility = Facility::new(42, "FACILITY_UI");
+/// XAML parser.
+pub const FACILITY_XAML: Facility = Facility::new(43, "FACILITY_XAML");
+/// Action queue.
+pub const FACILITY_ACTION_QUEUE: Facility = Facility::new(44, "FACILITY_ACTION_QUEUE");
+/// Performance logs and alerts.
+pub const FACILITY_PLA: Facility = Facility::new(48, "FACILITY_PLA");
+/// Full volume encryption (BitLocker).
+pub const FACILITY_FVE: Facility = Facility::new(49, "FACILITY_FVE");
+/// Windows filtering platform.
+pub const FACILITY_FWP: Facility = Facility::new(50, "FACILITY_FWP");
+/// Windows remote management.
+pub const FACILITY_WINRM: Facility = Facility::new(51, "FACILITY_WINRM");
+/// Network driver interface specification.
+pub const FACILITY_NDIS: Facility = Facility::new(52, "FACILITY_NDIS");
+/// User mode hypervisor components.
+pub const FACILITY_USERMODE_HYPERVISOR: Facility =
+    Facility::new(53, "FACILITY_USERMODE_HYPERVISOR");
+/// Configuration management infrastructure.
+pub const FACILITY_CMI: Facility = Facility::new(54, "FACILITY_CMI");
+/// User mode virtualization subsystem.
+pub const FACILITY_USERMODE_VIRTUALIZATION: Facility =
+    Facility::new(55, "FACILITY_USERMODE_VIRTUALIZATION");
+/// User mode volume manager.
+pub const FACILITY_USERMODE_VOLMGR: Facility = Facility::new(56, "FACILITY_USERMODE_VOLMGR");
+/// Boot configuration database.
+pub const FACILITY_BCD: Facility = Facility::new(57, "FACILITY_BCD");
+/// User mode virtual hard disk support.
+pub const FACILITY_USERMODE_VHD: Facility = Facility::new(58, "FACILITY_USERMODE_VHD");
+/// Host network service.
+pub const FACILITY_USERMODE_HNS: Facility = Facility::new(59, "FACILITY_USERMODE_HNS");
+/// System diagnostics.
+pub const FACILITY_SDIAG: Facility = Facility::new(60, "FACILITY_SDIAG");
+/// Windows web services.
+pub const FACILITY_WEBSERVICES: Facility = Facility::new(61, "FACILITY_WEBSERVICES");
+/// Windows push notifications.
+pub const FACILITY_WPN: Facility = Facility::new(62, "FACILITY_WPN");
+/// Windows store.
+pub const FACILITY_WINDOWS_STORE: Facility = Facility::new(63, "FACILITY_WINDOWS_STORE");
+/// Input subsystem.
+pub const FACILITY_INPUT: Facility = Facility::new(64, "FACILITY_INPUT");
+/// Extensible authentication protocol.
+pub const FACILITY_EAP: Facility = Facility::new(66, "FACILITY_EAP");
+/// Windows defender.
+pub const FACILITY_WINDOWS_DEFENDER: Facility = Facility::new(80, "FACILITY_WINDOWS_DEFENDER");
+/// Open packaging conventions.
+pub const FACILITY_OPC: Facility = Facility::new(81, "FACILITY_OPC");
+/// XML paper specification.
+pub const FACILITY_XPS: Facility = Facility::new(82, "FACILITY_XPS");
+/// Remote access service.
+pub const FACILITY_RAS: Facility = Facility::new(83, "FACILITY_RAS");
+/// Mobile broadband.
+pub const FACILITY_MBN: Facility = Facility::new(84, "FACILITY_MBN");
+/// Peer to peer internal components.
+pub const FACILITY_P2P_INT: Facility = Facility::new(98, "FACILITY_P2P_INT");
+/// Peer to peer.
+pub const FACILITY_P2P: Facility = Facility::new(99, "FACILITY_P2P");
+/// Device association framework.
+pub const FACILITY_DAF: Facility = Facility::new(100, "FACILITY_DAF");
+/// Bluetooth attribute protocol.
+pub const FACILITY_BLUETOOTH_ATT: Facility = Facility::new(101, "FACILITY_BLUETOOTH_ATT");
+/// Audio subsystem.
+pub const FACILITY_AUDIO: Facility = Facility::new(102, "FACILITY_AUDIO");
+/// Visual C++ runtime.
+pub const FACILITY_VISUALCPP: Facility = Facility::new(109, "FACILITY_VISUALCPP");
+/// Script engines.
+pub const FACILITY_SCRIPT: Facility = Facility::new(112, "FACILITY_SCRIPT");
+/// Parsers.
+pub const FACILITY_PARSE: Facility = Facility::new(113, "FACILITY_PARSE");
+/// Update sequence number journal.
+pub const FACILITY_USN: Facility = Facility::new(129, "FACILITY_USN");
+/// Downloadable sounds.
+pub const FACILITY_DLS: Facility = Facility::new(153, "FACILITY_DLS");
+/// Debuggers.
+pub const FACILITY_DEBUGGERS: Facility = Facility::new(176, "FACILITY_DEBUGGERS");
+/// Delivery optimization.
+pub const FACILITY_DELIVERY_OPTIMIZATION: Facility =
+    Facility::new(208, "FACILITY_DELIVERY_OPTIMIZATION");
+/// Software protection platform.
+pub const FACILITY_SPP: Facility = Facility::new(256, "FACILITY_SPP");
+
+static FACILITIES: [Facility; 76] = [
+    FACILITY_NULL,
+    FACILITY_RPC,
+    FACILITY_DISPATCH,
+    FACILITY_STORAGE,
+    FACILITY_ITF,
+    FACILITY_WIN32,
+    FACILITY_WINDOWS,
+    FACILITY_SECURITY,
+    FACILITY_CONTROL,
+    FACILITY_CERT,
+    FACILITY_INTERNET,
+    FACILITY_MEDIASERVER,
+    FACILITY_MSMQ,
+    FACILITY_SETUPAPI,
+    FACILITY_SCARD,
+    FACILITY_COMPLUS,
+    FACILITY_AAF,
+    FACILITY_URT,
+    FACILITY_ACS,
+    FACILITY_DPLAY,
+    FACILITY_UMI,
+    FACILITY_SXS,
+    FACILITY_WINDOWS_CE,
+    FACILITY_HTTP,
+    FACILITY_USERMODE_COMMONLOG,
+    FACILITY_WER,
+    FACILITY_USERMODE_FILTER_MANAGER,
+    FACILITY_BACKGROUNDCOPY,
+    FACILITY_CONFIGURATION,
+    FACILITY_STATE_MANAGEMENT,
+    FACILITY_METADIRECTORY,
+    FACILITY_WINDOWSUPDATE,
+    FACILITY_DIRECTORYSERVICE,
+    FACILITY_GRAPHICS,
+    FACILITY_SHELL,
+    FACILITY_TPM_SERVICES,
+    FACILITY_TPM_SOFTWARE,
+    FACILITY_UI,
+    FACILITY_XAML,
+    FACILITY_ACTION_QUEUE,
+    FACILITY_PLA,
+    FACILITY_FVE,
+    FACILITY_FWP,
+    FACILITY_WINRM,
+    FACILITY_NDIS,
+    FACILITY_USERMODE_HYPERVISOR,
+    FACILITY_CMI,
+    FACILITY_USERMODE_VIRTUALIZATION,
+    FACILITY_USERMODE_VOLMGR,
+    FACILITY_BCD,
+    FACILITY_USERMODE_VHD,
+    FACILITY_USERMODE_HNS,
+    FACILITY_SDIAG,
+    FACILITY_WEBSERVICES,
+    FACILITY_WPN,
+    FACILITY_WINDOWS_STORE,
+    FACILITY_INPUT,
+    FACILITY_EAP,
+    FACILITY_WINDOWS_DEFENDER,
+    FACILITY_OPC,
+    FACILITY_XPS,
+    FACILITY_RAS,
+    FACILITY_MBN,
+    FACILITY_P2P_INT,
+    FACILITY_P2P,
+    FACILITY_DAF,
+    FACILITY_BLUETOOTH_ATT,
+    FACILITY_AUDIO,
+    FACILITY_VISUALCPP,
+    FACILITY_SCRIPT,
+    FACILITY_PARSE,
+    FACILITY_USN,
+    FACILITY_DLS,
+    FACILITY_DEBUGGERS,
+    FACILITY_DELIVERY_OPTIMIZATION,
+    FACILITY_SPP,
+];
+
+/// Returns all the standard facilities, ordered by value.
+pub fn all() -> &'static [Facility] {
+    &FACILITIES
+}
+
+/// Returns the standard facility with the supplied value, if any.
+pub fn by_value(value: i32) -> Option<&'static Facility> {
+    FACILITIES.iter().find(|facility| facility.value == value)
+}
+
+/// Returns the symbolic name of the standard facility with the supplied value,
+/// or `FACILITY_UNKNOWN(0x...)` for custom or vendor facilities.
+pub fn describe_facility(value: i32) -> String {
+    match by_value(value) {
+        Some(facility) => facility.symbolic_name().to_string(),
+        None => format!("FACILITY_UNKNOWN(0x{:03X})", value),
+    }
+}
+
+/// Runtime registry of facilities, pre-seeded with the standard facilities,
+/// that applications can extend with their own custom facilities.
+#[derive(Clone, Debug)]
+pub struct FacilityRegistry {
+    facilities: HashMap<i32, Facility>,
+}
+
+impl FacilityRegistry {
+    /// Creates a new registry containing the standard facilities.
+    pub fn new() -> FacilityRegistry {
+        FacilityRegistry {
+            facilities: FACILITIES
+                .iter()
+                .map(|facility| (facility.value, *facility))
+                .collect(),
+        }
+    }
+
+    /// Registers a facility, returning the facility previously registered with
+    /// the same value, if any.
+    pub fn register(&mut self, facility: Facility) -> Option<Facility> {
+        self.facilities.insert(facility.value, facility)
+    }
+
+    /// Returns the symbolic name of the registered facility with the supplied value, if any.
+    pub fn name_of(&self, value: i32) -> Option<&'static str> {
+        self.facilities
+            .get(&value)
+            .map(|facility| facility.symbolic_name)
+    }
+}
+
+impl Default for FacilityRegistry {
+    fn default() -> FacilityRegistry {
+        FacilityRegistry::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_facilities_round_trip_through_by_value() {
+        assert!(!all().is_empty());
+        for facility in all() {
+            let found = by_value(facility.value()).unwrap();
+            assert_eq!(found.value(), facility.value());
+            assert_eq!(found.symbolic_name(), facility.symbolic_name());
+        }
+    }
+
+    #[test]
+    fn by_value_misses_unknown_facilities() {
+        assert_eq!(by_value(FACILITY_WIN32.value()), Some(&FACILITY_WIN32));
+        assert!(by_value(0x321).is_none());
+    }
+
+    #[test]
+    fn describe_facility_names_known_facilities() {
+        assert_eq!(describe_facility(7), "FACILITY_WIN32");
+    }
+
+    #[test]
+    fn describe_facility_formats_unknown_facilities() {
+        assert_eq!(describe_facility(0x321), "FACILITY_UNKNOWN(0x321)");
+    }
+
+    #[test]
+    fn registry_resolves_custom_facilities() {
+        let mut registry = FacilityRegistry::new();
+        assert_eq!(registry.name_of(0x200), None);
+        assert!(registry
+            .register(Facility::new(0x200, "FACILITY_CONTOSO"))
+            .is_none());
+        assert_eq!(registry.name_of(0x200), Some("FACILITY_CONTOSO"));
+    }
+
+    #[test]
+    fn registry_is_seeded_with_standard_facilities() {
+        let registry = FacilityRegistry::default();
+        for facility in all() {
+            assert_eq!(
+                registry.name_of(facility.value()),
+                Some(facility.symbolic_name())
+            );
+        }
+    }
+
+    #[test]
+    fn contains_groups_hresults_by_facility() {
+        let codes = [
+            HResult(0x8007_0002_u32 as i32),
+            HResult(0x8000_4005_u32 as i32),
+            HResult(0x8007_0005_u32 as i32),
+            HResult(0x8004_0200_u32 as i32),
+        ];
+        let win32: Vec<_> = codes
+            .iter()
+            .filter(|&&hr| FACILITY_WIN32.contains(hr))
+            .collect();
+        assert_eq!(win32, [&codes[0], &codes[2]]);
+        let itf: Vec<_> = codes
+            .iter()
+            .filter(|&&hr| FACILITY_ITF.contains(hr))
+            .collect();
+        assert_eq!(itf, [&codes[3]]);
+        assert!(FACILITY_NULL.contains(codes[1]));
+        assert_eq!(FACILITY_WIN32.code_range(), (0, 0xFFFF));
+    }
+
+    #[test]
+    fn contains_never_matches_facilities_wider_than_hresults() {
+        let wide = Facility::new(0x800, "FACILITY_WIDE");
+        assert!(wide.fits());
+        assert!(!wide.contains(HResult(0x8800_0001_u32 as i32)));
+    }
+
+    #[test]
+    fn facilities_with_the_same_value_are_equal() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Facility::new(7, "FACILITY_WIN32"));
+        set.insert(Facility::new(7, "FACILITY_WIN32_ALIAS"));
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&FACILITY_WIN32));
+        assert_ne!(FACILITY_WIN32, FACILITY_ITF);
+    }
+
+    #[test]
+    fn fits_checks_the_12_bit_field() {
+        assert!(Facility::from_u32(0xFFF, "FACILITY_MAX").fits());
+        assert!(!Facility::from_u32(0x1000, "FACILITY_TOO_WIDE").fits());
+        assert_eq!(Facility::from_u32(0xFFF, "FACILITY_MAX").as_u32(), 0xFFF);
+    }
+
+    #[test]
+    fn symbolic_name_owned_matches_borrowed_name() {
+        assert_eq!(
+            FACILITY_WIN32.symbolic_name_owned(),
+            FACILITY_WIN32.symbolic_name()
+        );
+    }
+}
@@ -0,0 +1,459 @@
+//! Rust abstraction of the HRESULT code layout documented at the crate root.
+
+use crate::facilities::{self, FACILITY_WIN32};
+use crate::severity::SeverityLevel;
+
+/// Equivalent of the `SUCCEEDED` macro; returns true if the HRESULT indicates success.
+pub fn succeeded(hr: i32) -> bool {
+    hr >= 0
+}
+
+/// Equivalent of the `FAILED` macro; returns true if the HRESULT indicates failure.
+pub fn failed(hr: i32) -> bool {
+    hr < 0
+}
+
+/// Equivalent of the `HRESULT_FROM_WIN32` macro; maps a Win32 error code into
+/// the `FACILITY_WIN32` HRESULT space.
+///
+/// Values that are already HRESULTs or zero (`ERROR_SUCCESS`) are returned unchanged.
+pub fn hresult_from_win32(win32_err: u32) -> i32 {
+    let hr = win32_err as i32;
+    if hr <= 0 {
+        hr
+    } else {
+        ((win32_err & 0x0000_FFFF) | ((FACILITY_WIN32.value() as u32) << 16) | 0x8000_0000) as i32
+    }
+}
+
+/// Inverse of `hresult_from_win32`; recovers the Win32 error code from an HRESULT
+/// whose high word is exactly `0x8007` (a failure in `FACILITY_WIN32` with the
+/// reserved R, C, N and r bits clear), or returns `None` for any other HRESULT.
+pub fn win32_from_hresult(hr: i32) -> Option<u32> {
+    let win32_failure = 0x8000_0000 | ((FACILITY_WIN32.value() as u32) << 16);
+    if (hr as u32) & 0xFFFF_0000 == win32_failure {
+        Some((hr as u32) & 0xFFFF)
+    } else {
+        None
+    }
+}
+
+/// Equivalent of the `HRESULT_FROM_NT` macro; maps an NTSTATUS value into an
+/// HRESULT by setting the N bit.
+pub fn hresult_from_nt(status: i32) -> i32 {
+    status | 0x1000_0000
+}
+
+/// Returns true if the N bit (bit 28) is set, marking a mapped NT status value.
+pub fn is_nt_mapped(hr: i32) -> bool {
+    (hr >> 28) & 0x1 == 0x1
+}
+
+/// Returns true if the r bit (bit 27) is set, marking a message id for a display
+/// string instead of a status value.
+pub fn is_message_id(hr: i32) -> bool {
+    (hr >> 27) & 0x1 == 0x1
+}
+
+/// Decodes every raw value yielded by the supplied iterator into an HResult.
+pub fn decode_all<I: IntoIterator<Item = i32>>(values: I) -> impl Iterator<Item = HResult> {
+    values.into_iter().map(HResult)
+}
+
+/// Extracts every `0x` prefixed, 8 digit hex token from a line of text, such as
+/// a log line, ignoring the surrounding text.
+pub fn parse_codes(line: &str) -> Vec<i32> {
+    line.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter_map(|token| {
+            let digits = token
+                .strip_prefix("0x")
+                .or_else(|| token.strip_prefix("0X"))?;
+            if digits.len() == 8 {
+                u32::from_str_radix(digits, 16)
+                    .ok()
+                    .map(|value| value as i32)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Decodes an untrusted raw value; this never panics, whatever the input.
+pub fn try_decode(value: i32) -> DecodeResult {
+    let hresult = HResult(value);
+    DecodeResult {
+        hresult,
+        facility_name: hresult.facility_name(),
+    }
+}
+
+/// Best-effort decoding of a raw value, as returned by `try_decode`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodeResult {
+    /// Decoded HRESULT.
+    pub hresult: HResult,
+    /// Symbolic name of the facility, if it's one of the standard facilities.
+    pub facility_name: Option<&'static str>,
+}
+
+impl DecodeResult {
+    /// Returns the raw value that was decoded.
+    pub fn value(&self) -> i32 {
+        self.hresult.0
+    }
+}
+
+/// HRESULT value, decoded as documented at the crate root:
+/// <pre>
+/// +-+-+-+-+-+---------------------+-------------------------------+
+/// |S|R|C|N|r|    Facility         |               Code            |
+/// +-+-+-+-+-+---------------------+-------------------------------+
+/// </pre>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HResult(pub i32);
+
+impl HResult {
+    /// Equivalent of the `MAKE_HRESULT` macro; composes an HRESULT from its S bit,
+    /// facility and code, leaving the reserved bits clear.
+    ///
+    /// The facility is masked to its 11 bits and the code to its 16 bits, so any bits
+    /// outside each field are always discarded rather than reported as an error.
+    pub fn make(severity: bool, facility: i32, code: i32) -> HResult {
+        HResult(((severity as i32) << 31) | ((facility & 0x7FF) << 16) | (code & 0xFFFF))
+    }
+
+    /// Composes a success HRESULT, with both the S and R bits clear.
+    pub fn success(facility: i32, code: i32) -> HResult {
+        HResult::with_severity_level(SeverityLevel::Success, facility, code)
+    }
+
+    /// Composes an informational HRESULT, with the R bit set as NT's second severity bit.
+    ///
+    /// The S bit is clear, so the HRESULT still indicates success.
+    pub fn informational(facility: i32, code: i32) -> HResult {
+        HResult::with_severity_level(SeverityLevel::Informational, facility, code)
+    }
+
+    /// Composes a warning HRESULT, with the S bit set and the R bit clear.
+    ///
+    /// HRESULTs only distinguish success from failure through the S bit, so the
+    /// HRESULT indicates failure and has the same bit pattern as an ordinary failure
+    /// HRESULT; for example `warning(7, 5)` equals `E_ACCESSDENIED`.
+    pub fn warning(facility: i32, code: i32) -> HResult {
+        HResult::with_severity_level(SeverityLevel::Warning, facility, code)
+    }
+
+    fn with_severity_level(level: SeverityLevel, facility: i32, code: i32) -> HResult {
+        HResult((i32::from(level) << 30) | HResult::make(false, facility, code).0)
+    }
+
+    /// Returns the NT style 2 bit severity carried by the S and R bits (bits 31 and 30).
+    ///
+    /// HRESULTs only define the 1 bit S model, and standard failure HRESULTs leave the
+    /// R bit clear, so they decode as `SeverityLevel::Warning` rather than
+    /// `SeverityLevel::Error`; `E_FAIL` is one example. Only NT-mapped HRESULTs carry a
+    /// meaningful NT severity. Use `is_failure` to check an ordinary HRESULT.
+    pub fn nt_severity_level(&self) -> SeverityLevel {
+        SeverityLevel::from_high_bits(self.0)
+    }
+
+    /// Returns the S bit (bit 31), set when the HRESULT indicates failure.
+    pub fn severity(&self) -> bool {
+        (self.0 >> 31) & 0x1 == 0x1
+    }
+
+    /// Returns the R bit (bit 30), corresponding to NT's second severity bit.
+    pub fn reserved_r(&self) -> bool {
+        (self.0 >> 30) & 0x1 == 0x1
+    }
+
+    /// Returns the C bit (bit 29), corresponding to NT's C field.
+    pub fn customer(&self) -> bool {
+        (self.0 >> 29) & 0x1 == 0x1
+    }
+
+    /// Returns the N bit (bit 28), set when the HRESULT is a mapped NT status value.
+    pub fn is_ntmapped(&self) -> bool {
+        is_nt_mapped(self.0)
+    }
+
+    /// Returns the r bit (bit 27), set when the HRESULT is a message id for a
+    /// display string instead of a status value.
+    pub fn is_message_id(&self) -> bool {
+        is_message_id(self.0)
+    }
+
+    /// Returns the facility code (bits 16 through 26).
+    pub fn facility(&self) -> i32 {
+        (self.0 >> 16) & 0x7FF
+    }
+
+    /// Returns the symbolic name of the facility, if it's one of the standard facilities.
+    pub fn facility_name(&self) -> Option<&'static str> {
+        facilities::by_value(self.facility()).map(|facility| facility.symbolic_name())
+    }
+
+    /// Returns the facility's status code (bits 0 through 15).
+    pub fn code(&self) -> i32 {
+        self.0 & 0xFFFF
+    }
+
+    /// Returns the underlying Win32 error code if this is a failure HRESULT in
+    /// `FACILITY_WIN32`, see `win32_from_hresult`.
+    pub fn as_win32(&self) -> Option<u32> {
+        win32_from_hresult(self.0)
+    }
+
+    /// Returns true if the HRESULT indicates success.
+    pub fn is_success(&self) -> bool {
+        succeeded(self.0)
+    }
+
+    /// Returns true if the HRESULT indicates failure.
+    pub fn is_failure(&self) -> bool {
+        failed(self.0)
+    }
+}
+
+/// Compares the bit pattern of the HRESULT with a raw signed value.
+impl PartialEq<i32> for HResult {
+    fn eq(&self, other: &i32) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Compares the bit pattern of the HRESULT with a raw unsigned value.
+impl PartialEq<u32> for HResult {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 as u32 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_e_fail() {
+        let hr = HResult(0x8000_4005_u32 as i32);
+        assert!(hr.severity());
+        assert!(!hr.reserved_r());
+        assert!(!hr.customer());
+        assert!(!hr.is_ntmapped());
+        assert!(!hr.is_message_id());
+        assert_eq!(hr.facility(), 0);
+        assert_eq!(hr.code(), 0x4005);
+        assert!(!hr.is_success());
+        assert!(hr.is_failure());
+    }
+
+    #[test]
+    fn decodes_s_ok() {
+        let hr = HResult(0);
+        assert!(!hr.severity());
+        assert!(!hr.reserved_r());
+        assert!(!hr.customer());
+        assert!(!hr.is_ntmapped());
+        assert!(!hr.is_message_id());
+        assert_eq!(hr.facility(), 0);
+        assert_eq!(hr.code(), 0);
+        assert!(hr.is_success());
+        assert!(!hr.is_failure());
+    }
+
+    #[test]
+    fn succeeded_and_failed_split_on_the_sign_bit() {
+        assert!(succeeded(0));
+        assert!(succeeded(0x7FFF_FFFF));
+        assert!(!failed(0x7FFF_FFFF));
+        assert!(failed(i32::MIN));
+        assert!(!succeeded(i32::MIN));
+        assert!(failed(0x8000_4005_u32 as i32));
+
+        assert!(HResult(0).is_success());
+        assert!(HResult(0x7FFF_FFFF).is_success());
+        assert!(HResult(i32::MIN).is_failure());
+        assert!(HResult(0x8000_4005_u32 as i32).is_failure());
+    }
+
+    #[test]
+    fn hresult_from_win32_maps_into_facility_win32() {
+        assert_eq!(hresult_from_win32(2), 0x8007_0002_u32 as i32);
+        assert_eq!(hresult_from_win32(0), 0);
+    }
+
+    #[test]
+    fn hresult_from_win32_passes_hresults_through() {
+        assert_eq!(hresult_from_win32(0x8000_4005), 0x8000_4005_u32 as i32);
+        assert_eq!(hresult_from_win32(0x8007_0002), 0x8007_0002_u32 as i32);
+    }
+
+    #[test]
+    fn try_decode_never_panics_and_round_trips() {
+        let edges = [0, 1, -1, i32::MIN, i32::MAX, 0x8007_0002_u32 as i32];
+        let sweep = (i32::MIN..=i32::MAX).step_by(0x0001_0003);
+        for value in edges.iter().cloned().chain(sweep) {
+            let decoded = try_decode(value);
+            assert_eq!(decoded.value(), value);
+            assert_eq!(decoded.hresult, HResult(value));
+            assert_eq!(decoded.facility_name, HResult(value).facility_name());
+        }
+        assert_eq!(
+            try_decode(0x8007_0002_u32 as i32).facility_name,
+            Some("FACILITY_WIN32")
+        );
+    }
+
+    #[test]
+    fn make_composes_e_fail_shaped_values() {
+        assert_eq!(
+            HResult::make(true, 0, 0x4005),
+            HResult(0x8000_4005_u32 as i32)
+        );
+        let hr = HResult::make(true, 7, 2);
+        assert_eq!(hr, HResult(0x8007_0002_u32 as i32));
+        assert!(hr.severity());
+        assert!(!hr.reserved_r());
+        assert!(!hr.customer());
+        assert!(!hr.is_ntmapped());
+        assert!(!hr.is_message_id());
+        assert_eq!(hr.facility(), 7);
+        assert_eq!(hr.code(), 2);
+        assert_eq!(HResult::make(false, 4, 0x200), HResult(0x0004_0200));
+    }
+
+    #[test]
+    fn make_masks_out_of_range_fields() {
+        assert_eq!(
+            HResult::make(true, 0x800, 1),
+            HResult(0x8000_0001_u32 as i32)
+        );
+        assert_eq!(HResult::make(false, 7, 0x1_0002), HResult(0x0007_0002));
+        assert_eq!(HResult::make(false, -1, -1), HResult(0x07FF_FFFF));
+    }
+
+    #[test]
+    fn win32_from_hresult_recovers_win32_codes() {
+        assert_eq!(win32_from_hresult(0x8007_0002_u32 as i32), Some(2));
+        assert_eq!(win32_from_hresult(hresult_from_win32(5)), Some(5));
+    }
+
+    #[test]
+    fn win32_from_hresult_rejects_other_hresults() {
+        assert_eq!(win32_from_hresult(0x8000_4005_u32 as i32), None);
+        assert_eq!(win32_from_hresult(0x0007_0002), None);
+        assert_eq!(win32_from_hresult(0xC007_0005_u32 as i32), None);
+        assert_eq!(win32_from_hresult(0xA007_0005_u32 as i32), None);
+        assert_eq!(win32_from_hresult(0x9007_0005_u32 as i32), None);
+        assert_eq!(win32_from_hresult(0x8807_0005_u32 as i32), None);
+    }
+
+    #[test]
+    fn severity_constructors_round_trip_through_nt_severity_level() {
+        let warning = HResult::warning(7, 5);
+        assert_eq!(warning, HResult(0x8007_0005_u32 as i32));
+        assert_eq!(warning.nt_severity_level(), SeverityLevel::Warning);
+        assert!(warning.is_failure());
+
+        let informational = HResult::informational(7, 5);
+        assert_eq!(informational, HResult(0x4007_0005));
+        assert_eq!(
+            informational.nt_severity_level(),
+            SeverityLevel::Informational
+        );
+        assert!(informational.is_success());
+
+        let success = HResult::success(7, 5);
+        assert_eq!(success, HResult(0x0007_0005));
+        assert_eq!(success.nt_severity_level(), SeverityLevel::Success);
+    }
+
+    #[test]
+    fn standard_failures_decode_as_nt_warnings() {
+        let e_fail = HResult(0x8000_4005_u32 as i32);
+        assert_eq!(e_fail.nt_severity_level(), SeverityLevel::Warning);
+        let nt_mapped = HResult(hresult_from_nt(0xC000_0005_u32 as i32));
+        assert_eq!(nt_mapped.nt_severity_level(), SeverityLevel::Error);
+    }
+
+    #[test]
+    fn parse_codes_extracts_codes_from_a_sentence() {
+        assert_eq!(
+            parse_codes("open failed with 0x80070002, retry failed with 0x80004005."),
+            vec![0x8007_0002_u32 as i32, 0x8000_4005_u32 as i32]
+        );
+    }
+
+    #[test]
+    fn parse_codes_returns_nothing_without_codes() {
+        assert!(parse_codes("everything went fine").is_empty());
+    }
+
+    #[test]
+    fn parse_codes_handles_prefix_case_and_token_length() {
+        assert_eq!(parse_codes("(0X8000FFFF)"), vec![0x8000_FFFF_u32 as i32]);
+        assert!(parse_codes("short 0x1234 long 0x123456789").is_empty());
+    }
+
+    #[test]
+    fn compares_with_raw_signed_and_unsigned_values() {
+        let e_fail = HResult(0x8000_4005_u32 as i32);
+        assert!(e_fail == 0x8000_4005_u32 as i32);
+        assert!(e_fail == -0x7FFF_BFFB_i32);
+        assert!(e_fail == 0x8000_4005_u32);
+        assert!(e_fail != 0x8000_4004_u32);
+        assert!(e_fail != 0_i32);
+    }
+
+    #[test]
+    fn as_win32_extracts_win32_codes() {
+        assert_eq!(HResult(0x8007_0005_u32 as i32).as_win32(), Some(5));
+        assert_eq!(HResult(0).as_win32(), None);
+        assert_eq!(HResult(0xC007_0005_u32 as i32).as_win32(), None);
+        assert_eq!(HResult(0xA007_0005_u32 as i32).as_win32(), None);
+        assert_eq!(HResult(0x9007_0005_u32 as i32).as_win32(), None);
+        assert_eq!(HResult(0x8807_0005_u32 as i32).as_win32(), None);
+    }
+
+    #[test]
+    fn decode_all_decodes_each_value() {
+        let raw = [0x8007_0002_u32 as i32, 0x8000_4005_u32 as i32, 0x0004_0200];
+        let decoded: Vec<HResult> = decode_all(raw.iter().cloned()).collect();
+        assert_eq!(decoded.len(), 3);
+        assert_eq!((decoded[0].facility(), decoded[0].code()), (7, 2));
+        assert_eq!((decoded[1].facility(), decoded[1].code()), (0, 0x4005));
+        assert_eq!((decoded[2].facility(), decoded[2].code()), (4, 0x200));
+    }
+
+    #[test]
+    fn decodes_the_n_bit() {
+        let raw = 0xD000_0005_u32 as i32;
+        assert!(is_nt_mapped(raw));
+        assert!(!is_message_id(raw));
+        assert!(HResult(raw).is_ntmapped());
+        assert!(!HResult(raw).is_message_id());
+    }
+
+    #[test]
+    fn decodes_the_r_bit() {
+        let raw = 0x0800_0000;
+        assert!(is_message_id(raw));
+        assert!(!is_nt_mapped(raw));
+        assert!(HResult(raw).is_message_id());
+        assert!(!HResult(raw).is_ntmapped());
+    }
+
+    #[test]
+    fn facility_name_resolves_standard_facilities() {
+        assert_eq!(
+            HResult(0x8000_4005_u32 as i32).facility_name(),
+            Some("FACILITY_NULL")
+        );
+        assert_eq!(
+            HResult(0x8007_0002_u32 as i32).facility_name(),
+            Some("FACILITY_WIN32")
+        );
+        assert_eq!(HResult::make(true, 0x321, 1).facility_name(), None);
+    }
+}
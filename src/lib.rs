@@ -59,3 +59,58 @@
 //!     Code - is the facility's status code
 //! </pre>
 //!
+
+pub mod classify;
+pub mod errors;
+pub mod facilities;
+pub mod hresult;
+#[cfg(feature = "win-format")]
+pub mod message;
+pub mod ntstatus;
+pub mod prelude;
+pub mod severities;
+pub mod severity;
+
+/// Packs a code, severity and facility into a value laid out as documented above,
+/// leaving the customer and reserved bits clear.
+///
+/// The code is masked to its 16 bits, the facility to its 12 bits and the severity
+/// to its 2 bits, so any bits outside each field are always discarded.
+pub const fn pack(id: i32, severity: i32, facility: i32) -> i32 {
+    ((severity & 0x3) << 30) | ((facility & 0xFFF) << 16) | (id & 0xFFFF)
+}
+
+/// Returns true if the customer code flag (bit 29) is set in a value laid out as
+/// documented above.
+pub fn is_customer_code(value: i32) -> bool {
+    (value >> 29) & 0x1 == 0x1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR_CODE: i32 = pack(5, 3, 7);
+
+    #[test]
+    fn pack_works_in_const_context() {
+        assert_eq!(ERROR_CODE, 0xC007_0005_u32 as i32);
+        assert_eq!(ERROR_CODE, pack(5, 3, 7));
+        assert_eq!(pack(0, 0, 0), 0);
+    }
+
+    #[test]
+    fn is_customer_code_reads_bit_29() {
+        assert!(is_customer_code(0x2000_0000));
+        assert!(is_customer_code(pack(5, 3, 7) | 1 << 29));
+        assert!(!is_customer_code(0xC007_0005_u32 as i32));
+    }
+
+    #[test]
+    fn pack_masks_out_of_range_fields() {
+        assert_eq!(pack(0x1_0000, 0, 0), 0);
+        assert_eq!(pack(-1, 0, 0), 0xFFFF);
+        assert_eq!(pack(5, 0, 0x1007), pack(5, 0, 7));
+        assert_eq!(pack(5, 7, 7), pack(5, 3, 7));
+    }
+}
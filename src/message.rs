@@ -0,0 +1,92 @@
+//! System message text lookup through `FormatMessageW`.
+
+#[cfg(windows)]
+mod sys {
+    use std::ffi::c_void;
+
+    pub const FORMAT_MESSAGE_ALLOCATE_BUFFER: u32 = 0x0000_0100;
+    pub const FORMAT_MESSAGE_IGNORE_INSERTS: u32 = 0x0000_0200;
+    pub const FORMAT_MESSAGE_FROM_SYSTEM: u32 = 0x0000_1000;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn FormatMessageW(
+            flags: u32,
+            source: *const c_void,
+            message_id: u32,
+            language_id: u32,
+            buffer: *mut u16,
+            size: u32,
+            arguments: *mut c_void,
+        ) -> u32;
+
+        pub fn LocalFree(memory: *mut c_void) -> *mut c_void;
+    }
+}
+
+/// Returns the localized system message text for the supplied Win32 error code
+/// or HRESULT, with trailing line breaks trimmed.
+///
+/// Returns `None` if the system has no message for the value, and always on
+/// platforms other than Windows.
+#[cfg(windows)]
+pub fn format_system_message(value: i32) -> Option<String> {
+    let mut buffer: *mut u16 = std::ptr::null_mut();
+    // SAFETY: with FORMAT_MESSAGE_ALLOCATE_BUFFER the buffer argument is a pointer to
+    // the pointer that receives the system allocated buffer, and inserts are ignored
+    // so no arguments are read.
+    let len = unsafe {
+        sys::FormatMessageW(
+            sys::FORMAT_MESSAGE_ALLOCATE_BUFFER
+                | sys::FORMAT_MESSAGE_FROM_SYSTEM
+                | sys::FORMAT_MESSAGE_IGNORE_INSERTS,
+            std::ptr::null(),
+            value as u32,
+            0,
+            &mut buffer as *mut *mut u16 as *mut u16,
+            0,
+            std::ptr::null_mut(),
+        )
+    };
+    if len == 0 || buffer.is_null() {
+        return None;
+    }
+    // SAFETY: on success the system allocated buffer holds `len` UTF-16 units, and
+    // it's released with LocalFree once copied.
+    let message = unsafe {
+        let message = String::from_utf16_lossy(std::slice::from_raw_parts(buffer, len as usize));
+        sys::LocalFree(buffer as *mut std::ffi::c_void);
+        message
+    };
+    Some(message.trim_end_matches(&['\r', '\n'][..]).to_string())
+}
+
+/// Returns the localized system message text for the supplied Win32 error code
+/// or HRESULT, with trailing line breaks trimmed.
+///
+/// Returns `None` if the system has no message for the value, and always on
+/// platforms other than Windows.
+#[cfg(not(windows))]
+pub fn format_system_message(_value: i32) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(windows)]
+    #[test]
+    fn formats_file_not_found() {
+        let message =
+            format_system_message(crate::errors::win32::ERROR_FILE_NOT_FOUND as i32).unwrap();
+        assert!(!message.is_empty());
+        assert!(!message.ends_with('\n'));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn returns_none_off_windows() {
+        assert_eq!(format_system_message(2), None);
+    }
+}
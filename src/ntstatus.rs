@@ -0,0 +1,116 @@
+//! Rust abstraction of the NTSTATUS code layout.
+
+use crate::hresult::{hresult_from_nt, HResult};
+use crate::severity::SeverityLevel;
+
+/// NTSTATUS value, decoded as follows:
+/// <pre>
+/// +---+-+-+-----------------------+-------------------------------+
+/// |Sev|C|N|     Facility          |               Code            |
+/// +---+-+-+-----------------------+-------------------------------+
+/// </pre>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NtStatus(pub i32);
+
+impl NtStatus {
+    /// Returns the severity code (bits 30 and 31).
+    pub fn severity(&self) -> SeverityLevel {
+        SeverityLevel::from_high_bits(self.0)
+    }
+
+    /// Returns the C bit (bit 29), set for customer defined codes.
+    pub fn customer(&self) -> bool {
+        (self.0 >> 29) & 0x1 == 0x1
+    }
+
+    /// Returns the N bit (bit 28), reserved for the NT facility mapping.
+    pub fn is_ntmapped(&self) -> bool {
+        (self.0 >> 28) & 0x1 == 0x1
+    }
+
+    /// Returns the facility code (bits 16 through 27).
+    pub fn facility(&self) -> i32 {
+        (self.0 >> 16) & 0xFFF
+    }
+
+    /// Returns the facility's status code (bits 0 through 15).
+    pub fn code(&self) -> i32 {
+        self.0 & 0xFFFF
+    }
+
+    /// Equivalent of the `NT_SUCCESS` macro; true for success and informational severities.
+    pub fn is_success(&self) -> bool {
+        self.0 >= 0
+    }
+
+    /// Equivalent of the `NT_INFORMATION` macro.
+    pub fn is_information(&self) -> bool {
+        self.severity() == SeverityLevel::Informational
+    }
+
+    /// Equivalent of the `NT_WARNING` macro.
+    pub fn is_warning(&self) -> bool {
+        self.severity() == SeverityLevel::Warning
+    }
+
+    /// Equivalent of the `NT_ERROR` macro.
+    pub fn is_error(&self) -> bool {
+        self.severity() == SeverityLevel::Error
+    }
+
+    /// Maps the NTSTATUS into an HRESULT with the N bit set.
+    pub fn to_hresult(&self) -> HResult {
+        HResult(hresult_from_nt(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_status_success() {
+        let status = NtStatus(0);
+        assert_eq!(status.severity(), SeverityLevel::Success);
+        assert!(!status.customer());
+        assert!(!status.is_ntmapped());
+        assert_eq!(status.facility(), 0);
+        assert_eq!(status.code(), 0);
+        assert!(status.is_success());
+        assert!(!status.is_error());
+    }
+
+    #[test]
+    fn decodes_status_access_violation() {
+        let status = NtStatus(0xC000_0005_u32 as i32);
+        assert_eq!(status.severity(), SeverityLevel::Error);
+        assert!(!status.customer());
+        assert!(!status.is_ntmapped());
+        assert_eq!(status.facility(), 0);
+        assert_eq!(status.code(), 5);
+        assert!(!status.is_success());
+        assert!(status.is_error());
+    }
+
+    #[test]
+    fn decodes_warning_status() {
+        // STATUS_BUFFER_OVERFLOW
+        let status = NtStatus(0x8000_0005_u32 as i32);
+        assert_eq!(status.severity(), SeverityLevel::Warning);
+        assert_eq!(status.code(), 5);
+        assert!(!status.is_success());
+        assert!(status.is_warning());
+        assert!(!status.is_error());
+    }
+
+    #[test]
+    fn to_hresult_sets_the_n_bit() {
+        let raw = hresult_from_nt(0xC000_0005_u32 as i32);
+        assert_eq!(raw, 0xD000_0005_u32 as i32);
+        let hr = HResult(raw);
+        assert!(hr.is_ntmapped());
+        assert_eq!(hr.facility(), 0);
+        assert_eq!(hr.code(), 5);
+        assert_eq!(NtStatus(0xC000_0005_u32 as i32).to_hresult(), hr);
+    }
+}
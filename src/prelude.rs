@@ -0,0 +1,9 @@
+//! Re-exports of the most commonly used items.
+//!
+//! ```
+//! use winerror_rs::prelude::*;
+//! ```
+
+pub use crate::hresult::{failed, hresult_from_win32, succeeded, HResult};
+pub use crate::ntstatus::NtStatus;
+pub use crate::severity::SeverityLevel;
@@ -0,0 +1,166 @@
+//! Standard severity codes defined in ntdef.h.
+//!
+//! These name the same 2 bit field modelled by `severity::SeverityLevel`; use
+//! `Severity::from` to get the named severity for a level.
+
+use crate::severity::SeverityLevel;
+use std::hash::{Hash, Hasher};
+
+/// Severity code paired with its symbolic name.
+#[derive(Clone, Copy, Debug)]
+pub struct Severity {
+    value: i32,
+    symbolic_name: &'static str,
+}
+
+/// Severities compare equal when their values match, regardless of symbolic name.
+impl PartialEq for Severity {
+    fn eq(&self, other: &Severity) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Severity {}
+
+impl Hash for Severity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl Severity {
+    /// Creates a new severity from its value and symbolic name.
+    pub const fn new(value: i32, symbolic_name: &'static str) -> Severity {
+        Severity {
+            value,
+            symbolic_name,
+        }
+    }
+
+    /// Returns the severity code.
+    pub const fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Creates a new severity from its unsigned value and symbolic name.
+    pub const fn from_u32(value: u32, symbolic_name: &'static str) -> Severity {
+        Severity::new(value as i32, symbolic_name)
+    }
+
+    /// Returns the severity code as an unsigned value, for bit packing.
+    pub const fn as_u32(&self) -> u32 {
+        self.value as u32
+    }
+
+    /// Returns true if the severity code fits in the 2 bit field of the layout
+    /// documented at the crate root.
+    pub const fn fits(&self) -> bool {
+        self.as_u32() & !0x3 == 0
+    }
+
+    /// Returns the symbolic name of the severity.
+    pub const fn symbolic_name(&self) -> &'static str {
+        self.symbolic_name
+    }
+
+    /// Returns an owned copy of the symbolic name of the severity.
+    pub fn symbolic_name_owned(&self) -> String {
+        self.symbolic_name.to_string()
+    }
+}
+
+/// Severity of codes reporting success (`00`).
+pub const STATUS_SEVERITY_SUCCESS: Severity = Severity::new(0, "STATUS_SEVERITY_SUCCESS");
+/// Severity of informational codes (`01`).
+pub const STATUS_SEVERITY_INFORMATIONAL: Severity =
+    Severity::new(1, "STATUS_SEVERITY_INFORMATIONAL");
+/// Severity of warning codes (`10`).
+pub const STATUS_SEVERITY_WARNING: Severity = Severity::new(2, "STATUS_SEVERITY_WARNING");
+/// Severity of error codes (`11`).
+pub const STATUS_SEVERITY_ERROR: Severity = Severity::new(3, "STATUS_SEVERITY_ERROR");
+
+impl From<SeverityLevel> for Severity {
+    fn from(level: SeverityLevel) -> Severity {
+        match level {
+            SeverityLevel::Success => STATUS_SEVERITY_SUCCESS,
+            SeverityLevel::Informational => STATUS_SEVERITY_INFORMATIONAL,
+            SeverityLevel::Warning => STATUS_SEVERITY_WARNING,
+            SeverityLevel::Error => STATUS_SEVERITY_ERROR,
+        }
+    }
+}
+
+static SEVERITIES: [Severity; 4] = [
+    STATUS_SEVERITY_SUCCESS,
+    STATUS_SEVERITY_INFORMATIONAL,
+    STATUS_SEVERITY_WARNING,
+    STATUS_SEVERITY_ERROR,
+];
+
+/// Returns all the standard severities, ordered by value.
+pub fn all() -> &'static [Severity] {
+    &SEVERITIES
+}
+
+/// Returns the standard severity with the supplied value, if any.
+pub fn by_value(value: i32) -> Option<&'static Severity> {
+    SEVERITIES.iter().find(|severity| severity.value == value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_four_severities_resolve() {
+        for value in 0..4 {
+            assert_eq!(by_value(value).unwrap().value(), value);
+        }
+        assert_eq!(
+            by_value(3).unwrap().symbolic_name(),
+            "STATUS_SEVERITY_ERROR"
+        );
+        assert!(by_value(4).is_none());
+    }
+
+    #[test]
+    fn converts_from_severity_level() {
+        assert_eq!(
+            Severity::from(SeverityLevel::Success),
+            STATUS_SEVERITY_SUCCESS
+        );
+        assert_eq!(
+            Severity::from(SeverityLevel::Informational),
+            STATUS_SEVERITY_INFORMATIONAL
+        );
+        assert_eq!(
+            Severity::from(SeverityLevel::Warning),
+            STATUS_SEVERITY_WARNING
+        );
+        assert_eq!(Severity::from(SeverityLevel::Error), STATUS_SEVERITY_ERROR);
+    }
+
+    #[test]
+    fn severities_with_the_same_value_are_equal() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Severity::new(3, "STATUS_SEVERITY_ERROR"));
+        set.insert(Severity::new(3, "SEVERITY_ERROR"));
+        assert_eq!(set.len(), 1);
+        assert_ne!(STATUS_SEVERITY_ERROR, STATUS_SEVERITY_WARNING);
+    }
+
+    #[test]
+    fn fits_checks_the_2_bit_field() {
+        assert!(Severity::from_u32(3, "STATUS_SEVERITY_ERROR").fits());
+        assert!(!Severity::from_u32(4, "STATUS_SEVERITY_TOO_WIDE").fits());
+        assert_eq!(STATUS_SEVERITY_ERROR.as_u32(), 3);
+    }
+
+    #[test]
+    fn symbolic_name_owned_matches_borrowed_name() {
+        assert_eq!(
+            STATUS_SEVERITY_ERROR.symbolic_name_owned(),
+            STATUS_SEVERITY_ERROR.symbolic_name()
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Rust abstraction of the 2 bit severity field documented at the crate root.
+//!
+//! The standard symbolic names of each level live in `severities`; convert a
+//! `SeverityLevel` into a `severities::Severity` to get them.
+
+use std::convert::TryFrom;
+
+/// Severity levels encoded in the `Sev` field of a code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SeverityLevel {
+    /// `00` - Success
+    Success,
+    /// `01` - Informational
+    Informational,
+    /// `10` - Warning
+    Warning,
+    /// `11` - Error
+    Error,
+}
+
+impl SeverityLevel {
+    /// Decodes the severity held in the two high bits (bits 31 and 30) of a value.
+    pub(crate) fn from_high_bits(value: i32) -> SeverityLevel {
+        match (value >> 30) & 0x3 {
+            0 => SeverityLevel::Success,
+            1 => SeverityLevel::Informational,
+            2 => SeverityLevel::Warning,
+            _ => SeverityLevel::Error,
+        }
+    }
+}
+
+impl TryFrom<i32> for SeverityLevel {
+    /// The rejected value, which does not fit in 2 bits.
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<SeverityLevel, i32> {
+        match value {
+            0 => Ok(SeverityLevel::Success),
+            1 => Ok(SeverityLevel::Informational),
+            2 => Ok(SeverityLevel::Warning),
+            3 => Ok(SeverityLevel::Error),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<SeverityLevel> for i32 {
+    fn from(level: SeverityLevel) -> i32 {
+        match level {
+            SeverityLevel::Success => 0,
+            SeverityLevel::Informational => 1,
+            SeverityLevel::Warning => 2,
+            SeverityLevel::Error => 3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_all_four_levels() {
+        let levels = [
+            (0, SeverityLevel::Success),
+            (1, SeverityLevel::Informational),
+            (2, SeverityLevel::Warning),
+            (3, SeverityLevel::Error),
+        ];
+        for &(value, level) in levels.iter() {
+            assert_eq!(SeverityLevel::try_from(value), Ok(level));
+            assert_eq!(i32::from(level), value);
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_values() {
+        assert_eq!(SeverityLevel::try_from(4), Err(4));
+        assert_eq!(SeverityLevel::try_from(i32::MAX), Err(i32::MAX));
+        assert_eq!(SeverityLevel::try_from(-1), Err(-1));
+    }
+}
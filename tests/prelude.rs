@@ -0,0 +1,17 @@
+use winerror_rs::prelude::*;
+
+#[test]
+fn prelude_exports_helpers() {
+    assert!(succeeded(0));
+    assert!(failed(0x8000_4005_u32 as i32));
+    assert_eq!(hresult_from_win32(2), 0x8007_0002_u32 as i32);
+}
+
+#[test]
+fn prelude_exports_types() {
+    let hr = HResult(hresult_from_win32(2));
+    assert_eq!(hr.facility(), 7);
+
+    let status = NtStatus(0xC000_0005_u32 as i32);
+    assert_eq!(status.severity(), SeverityLevel::Error);
+}